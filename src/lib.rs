@@ -1,4 +1,17 @@
-// #![feature(zero_one)] // TODO see below
+#![cfg_attr(not(feature = "std"), no_std)]
+// This crate predates Rust 2018/2021 and keeps a handful of idioms from that
+// era throughout (the `try!` macro, bare trait objects, `field: field`
+// struct literals, `if let Some(_) = ...` instead of `.is_some()`, and
+// `transmute` without an explicit turbofish) for consistency with the rest
+// of the file, rather than by oversight. Allow them explicitly so `-D
+// warnings` still catches genuinely new mistakes instead of just this.
+#![allow(
+    deprecated,
+    bare_trait_objects,
+    clippy::redundant_field_names,
+    clippy::redundant_pattern_matching,
+    clippy::missing_transmute_annotations,
+)]
 
 //! Crate for reading whitespace-separated values.
 //!
@@ -6,6 +19,29 @@
 //! describes types that can be parsed from whitespace-separated words,
 //! which includes eg. integers, tuples and vectors.
 //!
+//! By default the crate uses `std::io`, but it can be built for
+//! `no_std + alloc` environments (eg. firmware talking to a UART reader)
+//! with `--no-default-features --features no_std`. That configuration
+//! swaps in a tiny in-crate `BufRead`/`Error` shim (the private
+//! `no_std_io` module) for the `std::io` types that would normally be
+//! used, so the crate has no external dependency beyond `core`/`alloc`;
+//! embedders implement the shim's `BufRead` trait for whatever reader
+//! their firmware provides. [`parse_line`](fn.parse_line.html) and other
+//! stdin-backed helpers are only available with the `std` feature, but
+//! [`WhiteReader`](struct.WhiteReader.html), [`White`](trait.White.html),
+//! [`Lengthed`](struct.Lengthed.html) and [`parse_string`](fn.parse_string.html)
+//! work the same either way. (An earlier version of this backend depended
+//! on the third-party `core_io` crate instead of the in-crate shim, but
+//! `core_io`'s build script doesn't support any rustc released in the last
+//! several years, so it was dropped in favor of the shim above.)
+//!
+//! `WhiteReader` tokenizes with [`SplitAsciiWhitespace`](struct.SplitAsciiWhitespace.html),
+//! which is faster than splitting on `char`s and also tracks the byte
+//! offset a token started at; that's used to enrich
+//! [`WhiteError::ParseError`](enum.WhiteError.html#variant.ParseError) with
+//! the offending word's line and column, so a bad parse looks like `parse
+//! error at line 4, column 12 ("seven")` instead of an opaque variant.
+//!
 //! # Examples
 //!
 //! Basics
@@ -22,7 +58,7 @@
 //! # use whiteread::parse_line;
 //! let x: i32 = parse_line().unwrap();
 //! ```
-//! 
+//!
 //! Efficient reading from stdin (newline-agnostic) with [`WhiteReader`](struct.WhiteReader.html).
 //! Stops on error.
 //!
@@ -37,14 +73,59 @@
 //!
 //! If you want better error handling in while-let loops,
 //! use [`ok_or_none`](trait.WhiteResultExt.html#tymethod.ok_or_none)
+//!
+//! There's also a [`WhiteWrite`](trait.WhiteWrite.html) trait and a
+//! [`Writer`](struct.Writer.html) wrapper (`std` only) for writing the same
+//! shapes of values back out, space- and newline-separated:
+//!
+//! ```no_run
+//! # use whiteread::Writer;
+//! let o = std::io::stdout();
+//! let mut o = Writer::new(std::io::BufWriter::new(o.lock()));
+//! o.println((1, 2.0)).unwrap();
+//! ```
+
+// `#![no_std]` (above) makes rustc auto-inject `core` into the extern
+// prelude; outside `no_std` we're on 2015-edition name resolution, which
+// doesn't do that for us, so we have to ask for it ourselves.
+#[cfg(feature = "std")]
+extern crate core;
+extern crate alloc;
 
+#[cfg(feature = "std")]
 use std::io::{self, BufRead};
-use std::str::SplitWhitespace;
-use std::mem::transmute;
+#[cfg(not(feature = "std"))]
+mod no_std_io;
+#[cfg(not(feature = "std"))]
+use no_std_io as io;
+#[cfg(not(feature = "std"))]
+use io::BufRead;
+
+use core::str::SplitWhitespace;
+use core::mem::transmute;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::vec;
+use core::convert::TryInto;
+
+#[cfg(feature = "std")]
+mod write;
+#[cfg(feature = "std")]
+pub use write::{WhiteWrite, Writer};
 
 /// A streaming iterator yielding borrowed strings.
 pub trait StrStream {
     fn next(&mut self) -> io::Result<Option<&str>>;
+
+    /// Byte offset, within whatever the stream is reading from, just past
+    /// the token last returned by `next`.
+    ///
+    /// Implementations that can't track this (eg. the standard library's
+    /// `SplitWhitespace`, which works on `char`s rather than bytes) return
+    /// `None`; it's used to enrich `WhiteError::ParseError` with a column
+    /// when it's available.
+    fn position(&self) -> Option<usize> { None }
 }
 
 impl<'a> StrStream for SplitWhitespace<'a> {
@@ -53,6 +134,80 @@ impl<'a> StrStream for SplitWhitespace<'a> {
     }
 }
 
+/// A `StrStream` over a single `&str`, splitting on ASCII whitespace bytes
+/// (`' '`, `'\t'`, `'\n'`, `'\r'`, `'\x0b'`, `'\x0c'`) only.
+///
+/// Unlike [`SplitWhitespace`](std::str::SplitWhitespace) this never decodes
+/// UTF-8 while scanning for whitespace, which makes it noticeably faster for
+/// the common case of ASCII input; it also remembers the byte offset it's
+/// reached, which `next` exposes via [`position`](#method.position) so
+/// callers can pin down exactly where a token started (and
+/// [`from_parts`](#method.from_parts) lets parsing resume from that offset).
+///
+/// Because only ASCII bytes are treated as whitespace, this is *not* a
+/// drop-in replacement for `SplitWhitespace`: non-ASCII whitespace (eg.
+/// U+00A0 NO-BREAK SPACE) is no longer a separator but ordinary token
+/// content. [`WhiteReader`](struct.WhiteReader.html) uses this stream (to
+/// get the column tracking above), so that's a user-visible difference from
+/// [`parse_string`](fn.parse_string.html), which still goes through
+/// `SplitWhitespace` and keeps splitting on any Unicode whitespace.
+///
+/// # Examples
+/// ```
+/// use whiteread::{StrStream, SplitAsciiWhitespace};
+/// let mut s = SplitAsciiWhitespace::new("ab cd");
+/// assert_eq!(StrStream::next(&mut s).unwrap(), Some("ab"));
+/// assert_eq!(s.position(), 2);
+/// assert_eq!(StrStream::next(&mut s).unwrap(), Some("cd"));
+/// assert_eq!(s.position(), 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SplitAsciiWhitespace<'a> {
+    s: &'a str,
+    position: usize,
+}
+
+fn is_ascii_whitespace_byte(b: u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c)
+}
+
+impl<'a> SplitAsciiWhitespace<'a> {
+    /// Starts splitting `s` from the beginning.
+    pub fn new(s: &'a str) -> SplitAsciiWhitespace<'a> {
+        SplitAsciiWhitespace::from_parts(s, 0)
+    }
+
+    /// Resumes splitting `s`, skipping straight to byte offset `position`.
+    pub fn from_parts(s: &'a str, position: usize) -> SplitAsciiWhitespace<'a> {
+        SplitAsciiWhitespace { s: s, position: position }
+    }
+
+    /// Byte offset just past the token last returned by `next`.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<'a> StrStream for SplitAsciiWhitespace<'a> {
+    fn next(&mut self) -> io::Result<Option<&str>> {
+        let bytes = self.s.as_bytes();
+        let mut i = self.position;
+        while i < bytes.len() && is_ascii_whitespace_byte(bytes[i]) { i += 1; }
+        if i == bytes.len() {
+            self.position = i;
+            return Ok(None);
+        }
+        let start = i;
+        while i < bytes.len() && !is_ascii_whitespace_byte(bytes[i]) { i += 1; }
+        self.position = i;
+        Ok(Some(&self.s[start..i]))
+    }
+
+    fn position(&self) -> Option<usize> {
+        Some(SplitAsciiWhitespace::position(self))
+    }
+}
+
 // White trait ------------------------------------------------------------------------------------------
 
 /// Trait for values that can be parsed from stream of whitespace-separated words.
@@ -65,7 +220,7 @@ impl<'a> StrStream for SplitWhitespace<'a> {
 /// # Examples
 ///
 /// Using a trait directly
-/// 
+///
 /// ```
 /// use whiteread::White;
 /// let mut stream = "123".split_whitespace();
@@ -77,9 +232,12 @@ impl<'a> StrStream for SplitWhitespace<'a> {
 /// ```
 /// # use whiteread::parse_string;
 /// # use whiteread::Lengthed;
-/// // tuples (up to 3)
+/// // tuples (up to arity 8)
 /// assert_eq!(parse_string("2 1 3 4").ok(), Some( ((2, 1), (3, 4)) ));
 ///
+/// // fixed-size arrays
+/// assert_eq!(parse_string("2 1 3").ok(), Some( [2, 1, 3] ));
+///
 /// // eager vector
 /// assert_eq!(parse_string("2 1 3 4").ok(), Some( vec![2, 1, 3, 4] ));
 ///
@@ -95,6 +253,26 @@ pub trait White: Sized {
 
 pub type WhiteResult<T> = Result<T, WhiteError>;
 
+/// Extra context attached to a [`WhiteError::ParseError`](enum.WhiteError.html),
+/// pinpointing the word that failed to parse.
+///
+/// `offset` and `line` are only filled in when the `StrStream` that produced
+/// the error can track them: [`SplitAsciiWhitespace`](struct.SplitAsciiWhitespace.html)
+/// supplies `offset`, and [`WhiteReader`](struct.WhiteReader.html) (which is
+/// built on it) additionally supplies `line`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseErrorInfo {
+    /// The token that could not be parsed.
+    pub word: String,
+
+    /// Byte offset of `word` within the text the stream was splitting (for
+    /// `WhiteReader`, the current line), if known.
+    pub offset: Option<usize>,
+
+    /// 1-based line number, if known.
+    pub line: Option<usize>,
+}
+
 /// Error which can occur while parsing `White` object.
 ///
 /// It's convertible into `io::Error`, so it composes well with other reading functions.
@@ -105,19 +283,21 @@ pub type WhiteResult<T> = Result<T, WhiteError>;
 /// # use whiteread::{parse_string, TooShort, Leftovers, ParseError};
 /// if let Err(TooShort) = parse_string::<(u8, u16)>("1") {} else { panic!(); }
 /// if let Err(Leftovers) = parse_string::<char>("x y z") {} else { panic!(); }
-/// if let Err(ParseError) = parse_string::<i32>("seven") {} else { panic!(); }
+/// if let Err(ParseError(info)) = parse_string::<i32>("seven") {
+///     assert_eq!(info.word, "seven");
+/// } else { panic!(); }
 /// ```
 #[derive(Debug)]
 pub enum WhiteError {
     /// There was not enough input to parse a value.
     TooShort,
-    
+
     /// Excessive input was provided.
     Leftovers,
-    
+
     /// Parse error occured (data was in invalid format).
-    ParseError,
-    
+    ParseError(ParseErrorInfo),
+
     /// IO Error occured.
     IoError(io::Error)
 }
@@ -128,16 +308,23 @@ impl From<io::Error> for WhiteError {
     fn from(e: io::Error) -> WhiteError { IoError(e) }
 }
 
-impl std::error::Error for WhiteError {
+impl WhiteError {
     fn description(&self) -> &str {
         match *self {
             TooShort => "not enough input to parse a value",
             Leftovers => "excessive input provided",
-            ParseError => "parse error occured",
-            IoError(ref e) => e.description()
+            ParseError(_) => "parse error occured",
+            IoError(_) => "i/o error"
         }
     }
-    
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WhiteError {
+    fn description(&self) -> &str {
+        WhiteError::description(self)
+    }
+
     fn cause(&self) -> Option<&std::error::Error> {
         match *self {
             IoError(ref e) => e.cause(),
@@ -146,11 +333,18 @@ impl std::error::Error for WhiteError {
     }
 }
 
-impl std::fmt::Display for WhiteError {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
-        use std::error::Error;
+impl core::fmt::Display for WhiteError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
         match *self {
-            IoError(ref e) => e.fmt(fmt),
+            IoError(ref e) => core::fmt::Display::fmt(e, fmt),
+            ParseError(ref info) => match (info.line, info.offset) {
+                (Some(line), Some(offset)) =>
+                    write!(fmt, "parse error at line {}, column {} (\"{}\")", line, offset + 1, info.word),
+                (None, Some(offset)) =>
+                    write!(fmt, "parse error at column {} (\"{}\")", offset + 1, info.word),
+                (_, None) =>
+                    write!(fmt, "parse error (\"{}\")", info.word),
+            },
             _ => fmt.write_str(self.description())
         }
     }
@@ -182,7 +376,7 @@ pub trait WhiteResultExt<T> {
     /// while let Some(x) = try!( i.parse::<i64>().ok_or_none() ) { s += x }
     /// Ok(s)
     /// # }
-    /// 
+    ///
     fn ok_or_none(self) -> WhiteResult<Option<T>>;
 }
 
@@ -201,7 +395,18 @@ macro_rules! white {
     ($T:ident) => (
         impl White for $T {
             fn read<I: StrStream>(it: &mut I) -> WhiteResult<$T> {
-                try!( it.next() ).ok_or(TooShort).and_then( |s| s.parse().or(Err(ParseError)) )
+                let s = match try!( it.next() ) {
+                    Some(s) => s,
+                    None => return Err(TooShort),
+                };
+                match s.parse() {
+                    Ok(v) => Ok(v),
+                    Err(_) => {
+                        let word = String::from(s);
+                        let offset = it.position().map(|p| p.saturating_sub(word.len()));
+                        Err(ParseError(ParseErrorInfo { word: word, offset: offset, line: None }))
+                    }
+                }
             }
         }
     )
@@ -229,24 +434,31 @@ impl White for char {
     }
 }
 
-impl<T: White, U: White> White for (T, U) {
-    fn read<I: StrStream>(it: &mut I) -> WhiteResult<(T, U)> {
-        Ok( (try!( White::read(it) ), try!( White::read(it) )) )
-    }
-}
-
-impl<T: White, U: White, V: White> White for (T, U, V) {
-    fn read<I: StrStream>(it: &mut I) -> WhiteResult<(T, U, V)> {
-        Ok( (try!( White::read(it) ), try!( White::read(it) ), try!( White::read(it) )) )
-    }
-}
-
 impl White for () {
     fn read<I: StrStream>(_: &mut I) -> WhiteResult<()> {
         Ok(())
     }
 }
 
+// tuples (up to arity 8)
+macro_rules! white_tuple {
+    ($($T:ident),+) => (
+        impl<$($T: White),+> White for ($($T,)+) {
+            fn read<I: StrStream>(it: &mut I) -> WhiteResult<($($T,)+)> {
+                Ok(( $( try!( <$T as White>::read(it) ) ),+ ))
+            }
+        }
+    )
+}
+
+white_tuple!(A, B);
+white_tuple!(A, B, C);
+white_tuple!(A, B, C, D);
+white_tuple!(A, B, C, D, E);
+white_tuple!(A, B, C, D, E, F);
+white_tuple!(A, B, C, D, E, F, G);
+white_tuple!(A, B, C, D, E, F, G, H);
+
 impl<T: White> White for Vec<T> {
     fn read<I: StrStream>(it: &mut I) -> WhiteResult<Vec<T>> {
         let mut v = vec![];
@@ -255,6 +467,32 @@ impl<T: White> White for Vec<T> {
     }
 }
 
+/// Reads exactly `N` elements into a fixed-size array.
+///
+/// # Examples
+/// ```
+/// # use whiteread::parse_string;
+/// let [a, b, c]: [i64; 3] = parse_string("1 2 3").unwrap();
+/// assert_eq!((a, b, c), (1, 2, 3));
+/// ```
+///
+/// # Errors
+/// Returns `TooShort` as soon as the stream runs out before `N` elements
+/// have been read.
+impl<T: White, const N: usize> White for [T; N] {
+    fn read<I: StrStream>(it: &mut I) -> WhiteResult<[T; N]> {
+        let mut v = Vec::with_capacity(N);
+        for _ in 0..N {
+            v.push( try!( White::read(it) ) );
+        }
+        // v.len() == N by construction, so this can't fail.
+        match v.try_into() {
+            Ok(arr) => Ok(arr),
+            Err(_) => unreachable!()
+        }
+    }
+}
+
 /// Wrapper for reading vector of values represented by a list prepended by a number of elements.
 ///
 /// # Examples
@@ -278,21 +516,39 @@ impl<T: White> White for Lengthed<T> {
     }
 }
 
-// TODO: use cargo nightly feature for Zero
-// ~ /// Wrapper for reading vector of numbers represented by a list ending with 0.
-// ~ #[derive(Debug)]
-// ~ pub struct Zeroed<T>(pub Vec<T>);
-
-// ~ impl<T: White + std::num::Zero + PartialEq> White for Zeroed<T> {
-    // ~ fn read<I: StrStream>(it: &mut I) -> WhiteResult<Zeroed<T>> {
-        // ~ let mut v = vec![];
-        // ~ while let Some(x) = White::read(it) {
-            // ~ if x == std::num::Zero::zero() { return Some(Zeroed(v)); }
-            // ~ else { v.push(x) }
-        // ~ }
-        // ~ panic!("white: Zeroed Vec didn't end at 0");
-    // ~ }
-// ~ }
+/// Wrapper for reading a vector of values terminated by a caller-supplied
+/// sentinel, eg. the classic `...0`-terminated list.
+///
+/// Unlike `Lengthed`, the terminator isn't part of the stream format's
+/// length prefix – it's a value only the caller knows – so `Until` can't
+/// implement `White` (which takes no arguments); use `Until::read` directly,
+/// passing the terminator.
+///
+/// # Examples
+/// ```
+/// # use whiteread::Until;
+/// let mut stream = "1 2 3 0".split_whitespace();
+/// let Until(v, _): Until<i64> = Until::read(&mut stream, 0).unwrap();
+/// assert_eq!(v, &[1, 2, 3]);
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct Until<T>(pub Vec<T>, pub T);
+
+impl<T: White + PartialEq> Until<T> {
+    /// Reads `T` values until one equals `terminator`, which is consumed but
+    /// not pushed into the resulting vector.
+    ///
+    /// # Errors
+    /// Returns `TooShort` if the stream ends before the terminator is found.
+    pub fn read<I: StrStream>(it: &mut I, terminator: T) -> WhiteResult<Until<T>> {
+        let mut v = vec![];
+        loop {
+            let x: T = try!( White::read(it) );
+            if x == terminator { return Ok(Until(v, terminator)); }
+            v.push(x);
+        }
+    }
+}
 
 // Helpers ----------------------------------------------------------------------------------------------
 
@@ -308,6 +564,7 @@ impl<T: White> White for Lengthed<T> {
 /// # use whiteread::parse_line;
 /// let x: i32 = parse_line().unwrap();
 /// ```
+#[cfg(feature = "std")]
 pub fn parse_line<T: White>() -> WhiteResult<T> {
     let mut line = String::new();
     let n_bytes = try!( std::io::stdin().read_line(&mut line) );
@@ -326,7 +583,7 @@ pub fn parse_line<T: White>() -> WhiteResult<T> {
 pub fn parse_string<T: White>(s: &str) -> WhiteResult<T> {
     let mut stream = s.split_whitespace();
     let value = try!( White::read(&mut stream) );
-    
+
     if let Some(_) = Iterator::next(&mut stream) { Err(Leftovers) }
     else { Ok(value) }
 }
@@ -339,6 +596,14 @@ pub fn parse_string<T: White>(s: &str) -> WhiteResult<T> {
 /// scanf-like behavior (newline-agnostic parsing)
 /// and also provides almost zero-allocation parsing.
 ///
+/// Tokenizing is done with [`SplitAsciiWhitespace`](struct.SplitAsciiWhitespace.html)
+/// (for the line/column tracking it gives `WhiteError::ParseError`), which
+/// only treats ASCII bytes as whitespace. That's a user-visible difference
+/// from [`parse_string`](fn.parse_string.html): non-ASCII whitespace (eg.
+/// U+00A0 NO-BREAK SPACE) does *not* separate tokens here, even though
+/// `parse_string` (which uses `str::split_whitespace`) treats it as a
+/// separator.
+///
 /// # Examples
 ///
 /// This code
@@ -364,7 +629,7 @@ pub fn parse_string<T: White>(s: &str) -> WhiteResult<T> {
 ///
 ///
 /// Overview of how various methods handle newlines:
-/// 
+///
 /// ```
 /// # use whiteread::{WhiteReader,TooShort};
 /// let data = std::io::Cursor::new(b"1 2\n\n3 4 5\n6 7\n8\n" as &[u8]);
@@ -380,25 +645,41 @@ pub fn parse_string<T: White>(s: &str) -> WhiteResult<T> {
 /// #     Err(TooShort) => (),
 /// #     _ => panic!()
 /// # }
-/// # 
+/// #
 /// # match r.line::<u8>() {
 /// #     Err(TooShort) => (),
 /// #     _ => panic!()
 /// # }
-/// # 
+/// #
 /// # match r.next_line() {
 /// #     Err(TooShort) => (),
 /// #     _ => panic!()
 /// # }
 /// ```
+///
+/// Unlike [`parse_string`](fn.parse_string.html), non-ASCII whitespace isn't
+/// a separator here – it ends up inside the token, which then fails to parse:
+///
+/// ```
+/// # use whiteread::{WhiteReader, ParseError, parse_string};
+/// assert_eq!(parse_string::<(i32, i32)>("1\u{a0}2").ok(), Some( (1, 2) ));
+///
+/// let data = std::io::Cursor::new("1\u{a0}2".as_bytes());
+/// let mut r = WhiteReader::new(data);
+/// match r.line::<(i32, i32)>() {
+///     Err(ParseError(info)) => assert_eq!(info.word, "1\u{a0}2"),
+///     _ => panic!()
+/// }
+/// ```
 pub struct WhiteReader<B: BufRead> {
     buf: B,
     line: String,
-    
+    line_no: usize,
+
     // We use 'static lifetime here, but it actually points into line's buffer.
     // We manualy check that after each mutation of line,
     // words are immediately updated.
-    words: SplitWhitespace<'static>
+    words: SplitAsciiWhitespace<'static>
 }
 
 /// # Constructors
@@ -407,7 +688,7 @@ impl<B: BufRead> WhiteReader<B> {
     ///
     /// Note that you don't have to pass an owned buffered reader, it could be also `&mut`.
     pub fn new(buf: B) -> WhiteReader<B> {
-        WhiteReader { buf: buf, line: String::new(), words: "".split_whitespace() }
+        WhiteReader { buf: buf, line: String::new(), line_no: 0, words: SplitAsciiWhitespace::new("") }
     }
 }
 
@@ -419,26 +700,41 @@ impl<B: BufRead> WhiteReader<B> {
 ///
 /// These methods may return `TooShort`, `ParseError` or `IoError` error variant.
 /// If they return other variants too, it is stated explicitely.
+///
+/// `ParseError`s raised while parsing are enriched with the 1-based line
+/// number they occured on (see [`ParseErrorInfo`](struct.ParseErrorInfo.html)).
 impl<B: BufRead> WhiteReader<B> {
     /// Parses a White value without specialy treating newlines (just like `scanf` or `cin>>`)
     pub fn parse<T: White>(&mut self) -> WhiteResult<T> {
-        White::read(self)
+        let r = White::read(self);
+        self.with_line_info(r)
     }
-    
+
     /// Just parse().unwrap().
-    /// 
+    ///
     /// Use it if you really value your time. ;)
     pub fn p<T: White>(&mut self) -> T { self.parse().unwrap() }
-    
+
+    fn with_line_info<T>(&self, r: WhiteResult<T>) -> WhiteResult<T> {
+        match r {
+            Err(ParseError(mut info)) => {
+                info.line = Some(self.line_no);
+                Err(ParseError(info))
+            }
+            other => other
+        }
+    }
+
     fn read_line(&mut self) -> io::Result<Option<()>> {
-        self.words = "".split_whitespace(); // keep it safe in case of early returns
+        self.words = SplitAsciiWhitespace::new(""); // keep it safe in case of early returns
         self.line.clear();
         let n_bytes = try!( self.buf.read_line(&mut self.line) );
-        self.words = unsafe { transmute(self.line.split_whitespace()) };
+        self.words = unsafe { transmute(SplitAsciiWhitespace::new(&self.line)) };
         if n_bytes == 0 { return Ok(None); }
+        self.line_no += 1;
         Ok(Some( () ))
     }
-    
+
     /// Reads a new line from input and parses it into White value **as a whole**.
     ///
     /// The function is called just `line` for brevity and also to
@@ -451,18 +747,20 @@ impl<B: BufRead> WhiteReader<B> {
         if let None = try!( self.read_line() ) { return Err(TooShort); };
         self.finish_line()
     }
-    
+
     /// Reads a new line from input and parses some part of it into White value.
     pub fn start_line<T: White>(&mut self) -> WhiteResult<T> {
         if let None = try!( self.read_line() ) { return Err(TooShort); };
-        White::read(&mut self.words)
+        let r = White::read(&mut self.words);
+        self.with_line_info(r)
     }
-    
+
     /// Parses some part of current line into White value.
     pub fn continue_line<T: White>(&mut self) -> WhiteResult<T> {
-        White::read(&mut self.words)
+        let r = White::read(&mut self.words);
+        self.with_line_info(r)
     }
-    
+
     /// Parses remaining part of current line into White value.
     ///
     /// It could be used with `T=()`, to just check if we're on the end of line.
@@ -471,8 +769,9 @@ impl<B: BufRead> WhiteReader<B> {
     ///
     /// Additionaly to usual parse errors, this method may also return `Leftovers`.
     pub fn finish_line<T: White>(&mut self) -> WhiteResult<T> {
-        let value = try!( White::read(&mut self.words) );
-        if let Some(_) = Iterator::next(&mut self.words) { Err(Leftovers) }
+        let r = White::read(&mut self.words);
+        let value = try!( self.with_line_info(r) );
+        if let Some(_) = try!( StrStream::next(&mut self.words) ) { Err(Leftovers) }
         else { Ok(value) }
     }
 }
@@ -489,7 +788,7 @@ impl<B: BufRead> WhiteReader<B> {
         if let None = try!( self.read_line() ) { return Err(TooShort); }
         Ok(&self.line)
     }
-    
+
     /// Gets underlying buffer back.
     pub fn unwrap(self) -> B { self.buf }
 }
@@ -501,7 +800,7 @@ impl<B: BufRead> StrStream for WhiteReader<B> {
             unsafe fn statify<T>(x: &mut T) -> &'static mut T {
                 transmute(x)
             }
-            
+
             match try!( StrStream::next(unsafe{ statify(&mut self.words) }) ) {
                 None => (),
                 some => return Ok(some)
@@ -509,4 +808,8 @@ impl<B: BufRead> StrStream for WhiteReader<B> {
             if let None = try!( self.read_line() ) { return Ok(None) };
         }
     }
+
+    fn position(&self) -> Option<usize> {
+        Some(self.words.position())
+    }
 }
@@ -0,0 +1,49 @@
+//! Minimal stand-in for the parts of `std::io` (`BufRead`, `Error`, `Result`)
+//! that this crate needs, used in place of `std::io` when built with
+//! `--no-default-features --features no_std`.
+//!
+//! There used to be a dependency on the `core_io` crate here, but its build
+//! script doesn't recognize any rustc released in the last several years and
+//! its source relies on nightly features removed from the compiler long ago,
+//! so it can't actually be built on any toolchain anymore. This module gives
+//! embedders the same shape of API (a `BufRead` trait with `read_line`, and
+//! an `Error`/`Result` pair) built only on `core`/`alloc`, so they implement
+//! [`BufRead`] for whatever reader their firmware provides (eg. a UART) and
+//! everything else in the crate works unchanged.
+
+use alloc::string::String;
+use core::fmt;
+
+/// Stand-in for `std::io::Error` in `no_std` builds.
+///
+/// Unlike `std::io::Error` this carries just a static message, since there's
+/// no portable `ErrorKind`/`Box<dyn Error>` story without `std`.
+#[derive(Debug)]
+pub struct Error(&'static str);
+
+impl Error {
+    /// Builds an `Error` carrying the given message.
+    pub fn new(message: &'static str) -> Error {
+        Error(message)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// Stand-in for `std::io::Result`.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Stand-in for `std::io::BufRead`, cut down to the one method this crate
+/// actually calls.
+///
+/// Implement this for your own reader (eg. a UART buffer) to use
+/// [`WhiteReader`](crate::WhiteReader) in a `no_std + alloc` build.
+pub trait BufRead {
+    /// Reads a line of input (including the trailing `\n`, if any) into
+    /// `buf`, returning the number of bytes read (`0` at end of input).
+    fn read_line(&mut self, buf: &mut String) -> Result<usize>;
+}
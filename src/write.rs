@@ -0,0 +1,289 @@
+//! Mirror of the reading side of the crate: a [`WhiteWrite`](trait.WhiteWrite.html)
+//! trait and a [`Writer`](struct.Writer.html) wrapper for writing the same
+//! shapes of values that [`White`](../trait.White.html) reads, whitespace-separated.
+//!
+//! A value read with `White` round-trips back out through `WhiteWrite`:
+//!
+//! ```
+//! # use whiteread::{parse_string, Writer};
+//! let v: Vec<i32> = parse_string("2 1 3 4").unwrap();
+//! let mut out = Writer::new(Vec::new());
+//! out.println(v).unwrap();
+//! assert_eq!(out.unwrap(), b"2 1 3 4\n");
+//! ```
+//!
+//! `[T; N]` round-trips the same way:
+//!
+//! ```
+//! # use whiteread::Writer;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut out = Writer::new(Vec::new());
+//! out.println([1, 2, 3])?;
+//! assert_eq!(out.unwrap(), b"1 2 3\n");
+//! # Ok(()) }
+//! ```
+
+use std::io::{self, Write};
+
+use super::Lengthed;
+
+/// Trait for values that can be written as whitespace-separated words.
+///
+/// Implementations for primitives write a single word. Implementations for
+/// tuples write their elements left to right, separated by a single space.
+/// `Vec<T>` writes its elements space-separated; `Lengthed<T>` writes its
+/// length first, then its elements – the same shape `White` expects back.
+///
+/// # Examples
+///
+/// Using the trait directly
+///
+/// ```
+/// use whiteread::WhiteWrite;
+/// let mut buf = Vec::new();
+/// 123i32.write(&mut buf).unwrap();
+/// assert_eq!(buf, b"123");
+/// ```
+pub trait WhiteWrite {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()>;
+}
+
+macro_rules! white_write {
+    ($T:ty) => (
+        impl WhiteWrite for $T {
+            fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+                write!(w, "{}", self)
+            }
+        }
+    )
+}
+
+white_write!(bool);
+white_write!(u8);
+white_write!(u16);
+white_write!(u32);
+white_write!(u64);
+white_write!(usize);
+white_write!(i8);
+white_write!(i16);
+white_write!(i32);
+white_write!(i64);
+white_write!(isize);
+white_write!(f32);
+white_write!(f64);
+white_write!(char);
+white_write!(String);
+white_write!(str);
+
+impl<T: WhiteWrite + ?Sized> WhiteWrite for &T {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        WhiteWrite::write(*self, w)
+    }
+}
+
+// tuples (up to arity 8, matching `White`)
+macro_rules! white_write_tuple {
+    ($first:ident $(, $rest:ident)+) => (
+        impl<$first: WhiteWrite $(, $rest: WhiteWrite)+> WhiteWrite for ($first, $($rest,)+) {
+            #[allow(non_snake_case)]
+            fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+                let (ref $first, $(ref $rest),+) = *self;
+                try!( $first.write(w) );
+                $(
+                    try!( w.write_all(b" ") );
+                    try!( $rest.write(w) );
+                )+
+                Ok(())
+            }
+        }
+    )
+}
+
+white_write_tuple!(A, B);
+white_write_tuple!(A, B, C);
+white_write_tuple!(A, B, C, D);
+white_write_tuple!(A, B, C, D, E);
+white_write_tuple!(A, B, C, D, E, F);
+white_write_tuple!(A, B, C, D, E, F, G);
+white_write_tuple!(A, B, C, D, E, F, G, H);
+
+impl WhiteWrite for () {
+    fn write<W: Write>(&self, _: &mut W) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: WhiteWrite> WhiteWrite for Vec<T> {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        for (i, x) in self.iter().enumerate() {
+            if i > 0 { try!( w.write_all(b" ") ); }
+            try!( x.write(w) );
+        }
+        Ok(())
+    }
+}
+
+impl<T: WhiteWrite, const N: usize> WhiteWrite for [T; N] {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        for (i, x) in self.iter().enumerate() {
+            if i > 0 { try!( w.write_all(b" ") ); }
+            try!( x.write(w) );
+        }
+        Ok(())
+    }
+}
+
+/// # Examples
+///
+/// A value read with `Lengthed`'s `White` impl round-trips back out here:
+///
+/// ```
+/// # use whiteread::{parse_string, Lengthed, Writer};
+/// let v: Lengthed<u8> = parse_string("3 5 6 7").unwrap();
+/// let mut out = Writer::new(Vec::new());
+/// out.println(v).unwrap();
+/// assert_eq!(out.unwrap(), b"3 5 6 7\n");
+/// ```
+impl<T: WhiteWrite> WhiteWrite for Lengthed<T> {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        try!( self.0.len().write(w) );
+        if !self.0.is_empty() {
+            try!( w.write_all(b" ") );
+            try!( self.0.write(w) );
+        }
+        Ok(())
+    }
+}
+
+// Writer -------------------------------------------------------------------------------------------
+
+/// Wrapper for a `Write` allowing easy writing of `WhiteWrite` values,
+/// newline-agnostic in the same spirit as `WhiteReader`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use whiteread::Writer;
+/// let o = std::io::stdout();
+/// let mut o = Writer::new(std::io::BufWriter::new(o.lock()));
+/// o.println((1, 2.0)).unwrap();
+/// ```
+pub struct Writer<W: Write> {
+    w: W,
+
+    // Whether the next `print` needs a leading space to separate it from
+    // something already written on the current line.
+    mid_line: bool
+}
+
+/// # Constructors
+impl<W: Write> Writer<W> {
+    /// Wraps a `Write`.
+    ///
+    /// Note that you don't have to pass an owned writer, it could be also `&mut`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use whiteread::Writer;
+    /// let mut out = Writer::new(Vec::new());
+    /// out.println(1).unwrap();
+    /// assert_eq!(out.unwrap(), b"1\n");
+    /// ```
+    pub fn new(w: W) -> Writer<W> {
+        Writer { w: w, mid_line: false }
+    }
+}
+
+/// # Writing methods
+///
+/// Overview of how `print`, `println` and `newline` interact to space out
+/// values on a line:
+///
+/// ```
+/// # use whiteread::Writer;
+/// let mut out = Writer::new(Vec::new());
+/// out.print(1).unwrap();    // no leading space: first thing on the line
+/// out.print(2).unwrap();    // separated from `1` by a single space
+/// out.println(3).unwrap();  // separated from `2`, then ends the line
+/// out.print(4).unwrap();    // first thing on the new line, so no leading space
+/// assert_eq!(out.unwrap(), b"1 2 3\n4");
+/// ```
+impl<W: Write> Writer<W> {
+    /// Writes a value, preceded by a single space if it isn't the first
+    /// thing written on the current line.
+    ///
+    /// # Examples
+    /// ```
+    /// # use whiteread::Writer;
+    /// let mut out = Writer::new(Vec::new());
+    /// out.print(1).unwrap();
+    /// out.print(2).unwrap();
+    /// assert_eq!(out.unwrap(), b"1 2");
+    /// ```
+    pub fn print<T: WhiteWrite>(&mut self, x: T) -> io::Result<()> {
+        if self.mid_line { try!( self.w.write_all(b" ") ); }
+        try!( x.write(&mut self.w) );
+        self.mid_line = true;
+        Ok(())
+    }
+
+    /// Like `print`, but also ends the line afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// # use whiteread::Writer;
+    /// let mut out = Writer::new(Vec::new());
+    /// out.println((1, 2.0)).unwrap();
+    /// out.println(3).unwrap();
+    /// assert_eq!(out.unwrap(), b"1 2\n3\n");
+    /// ```
+    pub fn println<T: WhiteWrite>(&mut self, x: T) -> io::Result<()> {
+        try!( self.print(x) );
+        self.newline()
+    }
+
+    /// Ends the current line, so that the next `print` starts a fresh one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use whiteread::Writer;
+    /// let mut out = Writer::new(Vec::new());
+    /// out.print(1).unwrap();
+    /// out.newline().unwrap();
+    /// out.print(2).unwrap();
+    /// assert_eq!(out.unwrap(), b"1\n2");
+    /// ```
+    pub fn newline(&mut self) -> io::Result<()> {
+        try!( self.w.write_all(b"\n") );
+        self.mid_line = false;
+        Ok(())
+    }
+}
+
+/// # Additional methods
+impl<W: Write> Writer<W> {
+    /// Flushes the underlying writer.
+    ///
+    /// # Examples
+    /// ```
+    /// # use whiteread::Writer;
+    /// let mut out = Writer::new(Vec::new());
+    /// out.print(1).unwrap();
+    /// out.flush().unwrap();
+    /// assert_eq!(out.unwrap(), b"1");
+    /// ```
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.w.flush()
+    }
+
+    /// Gets underlying writer back.
+    ///
+    /// # Examples
+    /// ```
+    /// # use whiteread::Writer;
+    /// let mut out = Writer::new(Vec::new());
+    /// out.print(1).unwrap();
+    /// assert_eq!(out.unwrap(), b"1");
+    /// ```
+    pub fn unwrap(self) -> W { self.w }
+}